@@ -0,0 +1,97 @@
+//! Pluggable feed-resolution backends, one per RSS-exposing site.
+//!
+//! [`Backend`] is dispatched over the [`Backends`] enum via
+//! `enum_dispatch` so new sites (PeerTube, Vimeo, BitChute, Mixcloud, ...)
+//! can be added as a new variant without touching `App::run`/`run_file`.
+
+use crate::{ChannelFeed, HTMLParser, Result, YoutubeClient, YoutubeUrl};
+use async_trait::async_trait;
+use enum_dispatch::enum_dispatch;
+use url::Url;
+
+#[async_trait]
+#[enum_dispatch]
+pub trait Backend {
+    /// Resolves `url` (already known to belong to this backend) to its
+    /// RSS feed, fetching the channel page via `client` if needed.
+    async fn resolve_feed(&self, client: &YoutubeClient, url: &Url) -> Result<ChannelFeed>;
+}
+
+/// YouTube channel/playlist pages, resolved via [`HTMLParser`] or a
+/// direct `playlist_id=` feed URL for playlist links.
+pub struct YoutubeBackend;
+
+impl YoutubeBackend {
+    pub fn matches(url: &Url) -> bool {
+        url.host_str()
+            .map(|host| host.contains("youtube.com") || host.contains("youtu.be"))
+            .unwrap_or(false)
+    }
+}
+
+#[async_trait]
+impl Backend for YoutubeBackend {
+    async fn resolve_feed(&self, client: &YoutubeClient, url: &Url) -> Result<ChannelFeed> {
+        let youtube_url = YoutubeUrl::new(url.as_str())?;
+        if let Some(playlist_id) = youtube_url.playlist_id() {
+            return Ok(ChannelFeed {
+                url: format!(
+                    "https://www.youtube.com/feeds/videos.xml?playlist_id={playlist_id}"
+                ),
+                title: None,
+            });
+        }
+
+        // youtube.com sometimes rate-limits/geoblocks the direct fetch, or
+        // serves a page with neither the RSS `<link>` nor a scrapeable
+        // channel ID; fall back to a mirror instance rather than dropping
+        // the channel from a bulk `file` run.
+        match client.fetch_html(&youtube_url).await {
+            Ok(html_content) => match HTMLParser::extract_feed_url(&html_content) {
+                Ok(feed_url) => Ok(ChannelFeed {
+                    url: feed_url,
+                    title: HTMLParser::extract_channel_title(&html_content),
+                }),
+                Err(_) => Ok(ChannelFeed {
+                    url: client.resolve_via_fallback(url).await?,
+                    title: None,
+                }),
+            },
+            Err(_) => Ok(ChannelFeed {
+                url: client.resolve_via_fallback(url).await?,
+                title: None,
+            }),
+        }
+    }
+}
+
+#[enum_dispatch(Backend)]
+pub enum Backends {
+    Youtube(YoutubeBackend),
+}
+
+/// Picks the backend that can resolve `url`, or `None` if no backend
+/// recognizes its host.
+pub fn for_url(url: &Url) -> Option<Backends> {
+    if YoutubeBackend::matches(url) {
+        return Some(Backends::Youtube(YoutubeBackend));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_url_matches_youtube() {
+        let url = Url::parse("https://www.youtube.com/@somechannel").unwrap();
+        assert!(for_url(&url).is_some());
+    }
+
+    #[test]
+    fn test_for_url_rejects_unknown_host() {
+        let url = Url::parse("https://example.com/channel").unwrap();
+        assert!(for_url(&url).is_none());
+    }
+}
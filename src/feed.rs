@@ -0,0 +1,278 @@
+//! Fetches and parses a channel's `videos.xml` feed into structured
+//! [`VideoEntry`] records. Gated behind the `rss` cargo feature.
+
+use crate::{AppError, Result, YoutubeClient};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+/// A single video parsed out of a channel's Atom/`media:`/`yt:` feed.
+#[derive(Debug, Clone, Default)]
+pub struct VideoEntry {
+    pub video_id: String,
+    pub title: String,
+    pub author: String,
+    pub published: String,
+    pub thumbnail_url: Option<String>,
+    pub watch_url: String,
+}
+
+/// Fetches `feed_url` and parses it into a list of video entries.
+pub async fn fetch_entries(client: &YoutubeClient, feed_url: &str) -> Result<Vec<VideoEntry>> {
+    let xml = client.fetch_text(feed_url).await?;
+    parse_entries(&xml)
+}
+
+/// Streams the feed XML with a pull `Reader` instead of loading it into a
+/// DOM, tolerating YouTube's mixed Atom/`media:`/`yt:` namespaces.
+pub fn parse_entries(xml: &str) -> Result<Vec<VideoEntry>> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut entries = Vec::new();
+    let mut current: Option<VideoEntry> = None;
+    let mut current_tag: Option<String> = None;
+    let mut buf = Vec::new();
+
+    loop {
+        match reader
+            .read_event_into(&mut buf)
+            .map_err(|e| AppError::UrlError(format!("Malformed feed XML: {e}")))?
+        {
+            // YouTube's feed XML uses self-closing tags for both `<link>`
+            // and `<media:thumbnail>` (e.g. `<link href="..."/>`), which
+            // quick-xml reports as `Event::Empty` rather than a
+            // `Start`/`End` pair, so both must be handled identically here.
+            Event::Start(e) | Event::Empty(e) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).into_owned();
+                match name.as_str() {
+                    "entry" => current = Some(VideoEntry::default()),
+                    "media:thumbnail" => {
+                        if let Some(entry) = current.as_mut() {
+                            if let Some(url) = attr(&e, b"url") {
+                                entry.thumbnail_url = Some(url);
+                            }
+                        }
+                    }
+                    "link" => {
+                        if let Some(entry) = current.as_mut() {
+                            if let Some(href) = attr(&e, b"href") {
+                                entry.watch_url = href;
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+                current_tag = Some(name);
+            }
+            Event::Text(e) => {
+                if let (Some(entry), Some(tag)) = (current.as_mut(), current_tag.as_deref()) {
+                    let text = unescape_xml(&String::from_utf8_lossy(e.as_ref()));
+                    match tag {
+                        "yt:videoId" => entry.video_id = text,
+                        "title" => entry.title = text,
+                        "name" => entry.author = text,
+                        "published" => entry.published = text,
+                        _ => {}
+                    }
+                }
+            }
+            Event::End(e) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).into_owned();
+                if name == "entry" {
+                    if let Some(entry) = current.take() {
+                        entries.push(entry);
+                    }
+                }
+                current_tag = None;
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(entries)
+}
+
+fn attr(start: &quick_xml::events::BytesStart, key: &[u8]) -> Option<String> {
+    start
+        .attributes()
+        .flatten()
+        .find(|a| a.key.as_ref() == key)
+        .map(|a| unescape_xml(&String::from_utf8_lossy(&a.value)))
+}
+
+/// Reverses the handful of XML entities YouTube's feed (and our own OPML
+/// writer) ever produces; avoids depending on quick-xml's unescape helpers,
+/// which have churned across releases.
+fn unescape_xml(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Renders entries as JSON, CSV, or a simple one-line-per-video text list.
+pub fn render(entries: &[VideoEntry], format: &str) -> String {
+    match format {
+        "json" => render_json(entries),
+        "csv" => render_csv(entries),
+        _ => render_text(entries),
+    }
+}
+
+fn render_text(entries: &[VideoEntry]) -> String {
+    entries
+        .iter()
+        .map(|entry| format!("{} - {} ({})", entry.published, entry.title, entry.watch_url))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_csv(entries: &[VideoEntry]) -> String {
+    let mut out = String::from("video_id,title,author,published,thumbnail_url,watch_url\n");
+    for entry in entries {
+        out.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            csv_escape(&entry.video_id),
+            csv_escape(&entry.title),
+            csv_escape(&entry.author),
+            csv_escape(&entry.published),
+            csv_escape(entry.thumbnail_url.as_deref().unwrap_or("")),
+            csv_escape(&entry.watch_url),
+        ));
+    }
+    out
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains(',') || value.contains('"') || value.contains('\n') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn render_json(entries: &[VideoEntry]) -> String {
+    let items: Vec<String> = entries
+        .iter()
+        .map(|entry| {
+            format!(
+                r#"{{"video_id":"{}","title":"{}","author":"{}","published":"{}","thumbnail_url":{},"watch_url":"{}"}}"#,
+                json_escape(&entry.video_id),
+                json_escape(&entry.title),
+                json_escape(&entry.author),
+                json_escape(&entry.published),
+                entry
+                    .thumbnail_url
+                    .as_deref()
+                    .map(|url| format!("\"{}\"", json_escape(url)))
+                    .unwrap_or_else(|| "null".to_string()),
+                json_escape(&entry.watch_url),
+            )
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+fn json_escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_FEED: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+    <feed xmlns:yt="http://www.youtube.com/xml/schemas/2015" xmlns:media="http://search.yahoo.com/mrss/" xmlns="http://www.w3.org/2005/Atom">
+      <entry>
+        <yt:videoId>abc123</yt:videoId>
+        <title>Some Video</title>
+        <link rel="alternate" href="https://www.youtube.com/watch?v=abc123"/>
+        <author><name>Some Channel</name></author>
+        <published>2024-01-01T00:00:00+00:00</published>
+        <media:group>
+          <media:thumbnail url="https://i.ytimg.com/vi/abc123/hqdefault.jpg"/>
+        </media:group>
+      </entry>
+    </feed>
+    "#;
+
+    #[test]
+    fn test_parse_entries() {
+        let entries = parse_entries(SAMPLE_FEED).unwrap();
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.video_id, "abc123");
+        assert_eq!(entry.title, "Some Video");
+        assert_eq!(entry.author, "Some Channel");
+        assert_eq!(entry.published, "2024-01-01T00:00:00+00:00");
+        assert_eq!(entry.watch_url, "https://www.youtube.com/watch?v=abc123");
+        assert_eq!(
+            entry.thumbnail_url.as_deref(),
+            Some("https://i.ytimg.com/vi/abc123/hqdefault.jpg")
+        );
+    }
+
+    #[test]
+    fn test_render_csv_escapes_commas() {
+        let entries = vec![VideoEntry {
+            video_id: "abc123".to_string(),
+            title: "Title, with comma".to_string(),
+            author: "Channel".to_string(),
+            published: "2024-01-01".to_string(),
+            thumbnail_url: None,
+            watch_url: "https://www.youtube.com/watch?v=abc123".to_string(),
+        }];
+
+        let csv = render(&entries, "csv");
+        assert!(csv.contains("\"Title, with comma\""));
+    }
+
+    #[test]
+    fn test_render_json_round_trips_fields() {
+        let entries = vec![VideoEntry {
+            video_id: "abc123".to_string(),
+            title: "Title".to_string(),
+            author: "Channel".to_string(),
+            published: "2024-01-01".to_string(),
+            thumbnail_url: None,
+            watch_url: "https://www.youtube.com/watch?v=abc123".to_string(),
+        }];
+
+        let json = render(&entries, "json");
+        assert!(json.contains(r#""video_id":"abc123""#));
+        assert!(json.contains(r#""thumbnail_url":null"#));
+    }
+
+    #[test]
+    fn test_render_json_escapes_control_characters() {
+        let entries = vec![VideoEntry {
+            video_id: "abc123".to_string(),
+            title: "Line one\nLine two\ttabbed".to_string(),
+            author: "Channel".to_string(),
+            published: "2024-01-01".to_string(),
+            thumbnail_url: None,
+            watch_url: "https://www.youtube.com/watch?v=abc123".to_string(),
+        }];
+
+        let json = render(&entries, "json");
+        assert!(!json.contains('\n'));
+        assert!(!json.contains('\t'));
+        assert!(json.contains(r#""title":"Line one\nLine two\ttabbed""#));
+    }
+}
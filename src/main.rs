@@ -1,12 +1,19 @@
 use clap::{Arg, Command, value_parser};
 use futures::stream::{self, StreamExt};
+use regex::Regex;
 use select::document::Document;
-use select::predicate::Name;
+use select::predicate::{Attr, Name, Predicate};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use thiserror::Error;
 use url::Url;
 
+mod backend;
+#[cfg(feature = "rss")]
+mod feed;
+
+use backend::Backend as _;
+
 const MAX_CONCURRENT_REQUESTS: usize = 10;
 
 #[derive(Error, Debug)]
@@ -25,6 +32,15 @@ pub enum AppError {
 
     #[error("Invalid URL: {0}")]
     InvalidUrl(#[from] url::ParseError),
+
+    #[error("Failed to fetch URL: HTTP status {0}")]
+    HttpStatus(reqwest::StatusCode),
+
+    #[error("No backend recognizes host: {0}")]
+    UnsupportedBackend(String),
+
+    #[error("All fallback instances exhausted resolving: {0}")]
+    InstancesExhausted(String),
 }
 
 type Result<T> = std::result::Result<T, AppError>;
@@ -50,6 +66,14 @@ impl YoutubeUrl {
     pub fn as_str(&self) -> &str {
         self.url.as_str()
     }
+
+    /// Returns the `list=` query parameter if this URL points at a playlist.
+    pub fn playlist_id(&self) -> Option<String> {
+        self.url
+            .query_pairs()
+            .find(|(key, _)| key == "list")
+            .map(|(_, value)| value.into_owned())
+    }
 }
 impl AsRef<str> for YoutubeUrl {
     fn as_ref(&self) -> &str {
@@ -57,31 +81,152 @@ impl AsRef<str> for YoutubeUrl {
     }
 }
 
+/// Default User-Agent sent with every request. YouTube serves a stripped
+/// page (often missing the RSS `<link>` tag) to non-browser-looking
+/// clients, so pretending to be a browser materially improves hit rate.
+const DEFAULT_USER_AGENT: &str =
+    "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/124.0.0.0 Safari/537.36";
+
+/// Public Invidious/Piped mirrors tried, in random order, when youtube.com
+/// itself throttles or blocks the direct fetch.
+const DEFAULT_INSTANCES: &[&str] = &["yewtu.be", "inv.nadeko.net", "piped.video"];
+
+/// Tunables for [`YoutubeClient`], threaded in from the `--timeout`,
+/// `--user-agent`, `--retries`, and `--instances` CLI flags. The TLS
+/// backend itself is chosen at compile time via the `default-tls`,
+/// `rustls-tls-webpki-roots`, and `rustls-tls-native-roots` cargo features
+/// forwarded to reqwest.
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    pub timeout: std::time::Duration,
+    pub user_agent: String,
+    pub retries: u32,
+    pub instances: Vec<String>,
+}
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            timeout: std::time::Duration::from_secs(30),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            retries: 0,
+            instances: DEFAULT_INSTANCES.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
 pub struct YoutubeClient {
     client: reqwest::Client,
+    retries: u32,
+    instances: Vec<String>,
+    dead_instances: std::sync::Mutex<std::collections::HashSet<usize>>,
 }
 impl YoutubeClient {
     pub fn new() -> Self {
+        Self::with_config(ClientConfig::default())
+    }
+
+    pub fn with_config(config: ClientConfig) -> Self {
         let client = reqwest::Client::builder()
-            .timeout(std::time::Duration::from_secs(30))
+            .timeout(config.timeout)
+            .user_agent(config.user_agent)
             .build()
             .unwrap_or_default();
 
-        Self { client }
+        Self {
+            client,
+            retries: config.retries,
+            instances: config.instances,
+            dead_instances: std::sync::Mutex::new(std::collections::HashSet::new()),
+        }
     }
 
     pub async fn fetch_html(&self, url: &YoutubeUrl) -> Result<String> {
-        let response = self.client.get(url.as_str()).send().await?;
+        self.fetch_text(url.as_str()).await
+    }
+
+    /// Resolves `url` (the original youtube.com channel URL) by re-fetching
+    /// its path from a rotating Invidious/Piped mirror instead, scraping
+    /// the channel ID out of whatever page the mirror returns. Instances
+    /// are shuffled per call, and an instance that errors is marked dead
+    /// for the rest of this client's lifetime so it isn't retried.
+    pub async fn resolve_via_fallback(&self, url: &Url) -> Result<String> {
+        use rand::seq::SliceRandom;
+
+        let mut candidates: Vec<usize> = {
+            let dead = self.dead_instances.lock().unwrap_or_else(|e| e.into_inner());
+            (0..self.instances.len())
+                .filter(|idx| !dead.contains(idx))
+                .collect()
+        };
+        candidates.shuffle(&mut rand::rng());
+
+        let query = url.query().map(|q| format!("?{q}")).unwrap_or_default();
+
+        for idx in candidates {
+            let instance = &self.instances[idx];
+            let candidate_url = format!("https://{instance}{}{query}", url.path());
+
+            let channel_id = match self.try_fetch_text(&candidate_url).await {
+                Ok(html) => HTMLParser::extract_channel_id_from_html(&html),
+                Err(_) => None,
+            };
+
+            match channel_id {
+                Some(channel_id) => {
+                    return Ok(format!(
+                        "https://www.youtube.com/feeds/videos.xml?channel_id={channel_id}"
+                    ));
+                }
+                None => {
+                    self.dead_instances
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .insert(idx);
+                }
+            }
+        }
+
+        Err(AppError::InstancesExhausted(url.to_string()))
+    }
+
+    /// Fetches the body of an arbitrary URL as text, used both for channel
+    /// pages and for feed XML once resolved. Transient failures (timeouts
+    /// and 5xx/429 responses) are retried with exponential backoff, up to
+    /// `retries` times; 404s and other client errors are not retried.
+    pub async fn fetch_text(&self, url: &str) -> Result<String> {
+        let mut attempt = 0;
+        loop {
+            match self.try_fetch_text(url).await {
+                Ok(text) => return Ok(text),
+                Err(err) if attempt < self.retries && Self::is_retryable(&err) => {
+                    attempt += 1;
+                    let backoff = std::time::Duration::from_millis(200 * 2u64.pow(attempt - 1));
+                    tokio::time::sleep(backoff).await;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    async fn try_fetch_text(&self, url: &str) -> Result<String> {
+        let response = self.client.get(url).send().await?;
 
         if !response.status().is_success() {
-            return Err(AppError::UrlError(format!(
-                "Failed to fetch URL: HTTP status {}",
-                response.status()
-            )));
+            return Err(AppError::HttpStatus(response.status()));
         }
 
-        let html_content = response.text().await?;
-        Ok(html_content)
+        let text = response.text().await?;
+        Ok(text)
+    }
+
+    fn is_retryable(err: &AppError) -> bool {
+        match err {
+            AppError::ReqwestError(e) => e.is_timeout(),
+            AppError::HttpStatus(status) => {
+                status.is_server_error() || *status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            }
+            _ => false,
+        }
     }
 }
 impl Default for YoutubeClient {
@@ -90,20 +235,90 @@ impl Default for YoutubeClient {
     }
 }
 
+/// A resolved RSS feed together with the channel name it belongs to, when
+/// known (playlist feeds are resolved without a fetch and have no title).
+#[derive(Debug, Clone)]
+pub struct ChannelFeed {
+    pub url: String,
+    pub title: Option<String>,
+}
+
 pub struct HTMLParser;
 impl HTMLParser {
+    /// Extracts the RSS feed URL from a fetched channel page.
+    ///
+    /// YouTube only serves the `<link title="RSS">` tag on some page
+    /// variants, so when it's missing this falls back to scraping the
+    /// channel ID and synthesizing the feed URL directly.
     pub fn extract_feed_url(html_content: &str) -> Result<String> {
         let document = Document::from(html_content);
-        document
+        if let Some(href) = document
             .find(Name("link"))
             .find(|node| {
                 node.attr("title") == Some("RSS")
                     && node.attr("type") == Some("application/rss+xml")
             })
             .and_then(|node| node.attr("href"))
-            .map(String::from)
+        {
+            return Ok(href.to_string());
+        }
+
+        Self::extract_channel_id(&document, html_content)
+            .map(|channel_id| {
+                format!("https://www.youtube.com/feeds/videos.xml?channel_id={channel_id}")
+            })
             .ok_or_else(|| AppError::RssNotFound("RSS feed URL not found".to_string()))
     }
+
+    /// Scrapes a `UC...` channel ID out of a standalone page, used when
+    /// resolving a channel through an Invidious/Piped mirror instance.
+    pub fn extract_channel_id_from_html(html_content: &str) -> Option<String> {
+        let document = Document::from(html_content);
+        Self::extract_channel_id(&document, html_content)
+    }
+
+    /// Scrapes a `UC...` channel ID out of the canonical link, the
+    /// `og:url` meta tag, or the page's inline JSON as a last resort.
+    fn extract_channel_id(document: &Document, html_content: &str) -> Option<String> {
+        let from_canonical = document
+            .find(Name("link").and(Attr("rel", "canonical")))
+            .find_map(|node| node.attr("href"))
+            .and_then(Self::channel_id_from_url);
+
+        let from_og_url = document
+            .find(Name("meta").and(Attr("property", "og:url")))
+            .find_map(|node| node.attr("content"))
+            .and_then(Self::channel_id_from_url);
+
+        from_canonical
+            .or(from_og_url)
+            .or_else(|| Self::channel_id_from_inline_json(html_content))
+    }
+
+    fn channel_id_from_url(url: &str) -> Option<String> {
+        url.split("/channel/")
+            .nth(1)
+            .map(|rest| rest.split(['/', '?', '&']).next().unwrap_or(rest))
+            .filter(|id| id.starts_with("UC"))
+            .map(String::from)
+    }
+
+    fn channel_id_from_inline_json(html_content: &str) -> Option<String> {
+        let re = Regex::new(r#""(?:channelId|externalId)":"(UC[A-Za-z0-9_-]+)""#)
+            .expect("static regex is valid");
+        re.captures(html_content)
+            .and_then(|caps| caps.get(1))
+            .map(|m| m.as_str().to_string())
+    }
+
+    /// Extracts the channel's display name from the `og:title` meta tag,
+    /// so OPML exports can show human names instead of raw feed URLs.
+    pub fn extract_channel_title(html_content: &str) -> Option<String> {
+        Document::from(html_content)
+            .find(Name("meta").and(Attr("property", "og:title")))
+            .find_map(|node| node.attr("content"))
+            .map(String::from)
+    }
 }
 
 pub struct Output;
@@ -141,6 +356,55 @@ impl Output {
         println!("URLs successfully written to file");
         Ok(())
     }
+
+    /// Writes an OPML 2.0 subscription list so the resolved feeds can be
+    /// imported into any RSS reader in one go.
+    pub fn write_opml(path: &Path, feeds: &[ChannelFeed]) -> Result<()> {
+        let output_path = Self::generate_output_filename(path).with_extension("opml");
+        println!("Writing to: {}", output_path.display());
+        use std::io::Write;
+
+        let mut file = std::fs::File::create(&output_path)?;
+        writeln!(file, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(file, r#"<opml version="2.0">"#)?;
+        writeln!(file, "  <head>")?;
+        writeln!(file, "    <title>ytrss subscriptions</title>")?;
+        writeln!(file, "  </head>")?;
+        writeln!(file, "  <body>")?;
+        for feed in feeds {
+            let name = feed.title.as_deref().unwrap_or(&feed.url);
+            let name = Self::escape_xml(name);
+            let xml_url = Self::escape_xml(&feed.url);
+            writeln!(
+                file,
+                r#"    <outline type="rss" text="{name}" title="{name}" xmlUrl="{xml_url}"/>"#
+            )?;
+        }
+        writeln!(file, "  </body>")?;
+        writeln!(file, "</opml>")?;
+
+        println!("OPML document successfully written to file");
+        Ok(())
+    }
+
+    fn escape_xml(value: &str) -> String {
+        value
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    }
+}
+
+fn host_of(url: &Url) -> String {
+    url.host_str().unwrap_or("unknown").to_string()
+}
+
+/// `Arg::default_value` needs a `&'static str`; the default instance list
+/// is built from `DEFAULT_INSTANCES` at startup, so it's leaked once here
+/// rather than requiring clap's `string` feature for an owned `String`.
+fn default_instances_value() -> &'static str {
+    Box::leak(DEFAULT_INSTANCES.join(",").into_boxed_str())
 }
 
 pub struct App {
@@ -148,18 +412,25 @@ pub struct App {
 }
 impl App {
     pub fn new() -> Self {
+        Self::with_config(ClientConfig::default())
+    }
+
+    pub fn with_config(config: ClientConfig) -> Self {
         Self {
-            client: Arc::new(YoutubeClient::new()),
+            client: Arc::new(YoutubeClient::with_config(config)),
         }
     }
 
-    pub async fn run(&self, url_str: &str) -> Result<String> {
-        let youtube_url = YoutubeUrl::new(url_str)?;
-        let html_content = self.client.fetch_html(&youtube_url).await?;
-        HTMLParser::extract_feed_url(&html_content)
+    pub async fn run(&self, url_str: &str) -> Result<ChannelFeed> {
+        let url = Url::parse(url_str)?;
+        let resolved = backend::for_url(&url)
+            .ok_or_else(|| AppError::UnsupportedBackend(host_of(&url)))?
+            .resolve_feed(&self.client, &url)
+            .await?;
+        Ok(resolved)
     }
 
-    pub async fn run_file(&self, file_path: &Path) -> Result<Vec<(String, Result<String>)>> {
+    pub async fn run_file(&self, file_path: &Path) -> Result<Vec<(String, Result<ChannelFeed>)>> {
         let content = std::fs::read_to_string(file_path)?;
 
         let urls: Vec<_> = content
@@ -182,9 +453,11 @@ impl App {
                 let client = Arc::clone(&client);
                 async move {
                     let result = async {
-                        let youtube_url = YoutubeUrl::new(&url)?;
-                        let html_content = client.fetch_html(&youtube_url).await?;
-                        HTMLParser::extract_feed_url(&html_content)
+                        let parsed = Url::parse(&url)?;
+                        backend::for_url(&parsed)
+                            .ok_or_else(|| AppError::UnsupportedBackend(host_of(&parsed)))?
+                            .resolve_feed(&client, &parsed)
+                            .await
                     }
                     .await;
 
@@ -205,11 +478,46 @@ impl Default for App {
 }
 
 fn cli() -> Command {
-    Command::new("ytrss")
+    let cmd = Command::new("ytrss")
         .version(env!("CARGO_PKG_VERSION"))
         .subcommand_required(true)
         .arg_required_else_help(true)
         .about("Extract RSS feeds from YouTube URLs")
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .help("HTTP request timeout in seconds")
+                .value_name("SECS")
+                .value_parser(value_parser!(u64))
+                .default_value("30")
+                .global(true),
+        )
+        .arg(
+            Arg::new("user_agent")
+                .long("user-agent")
+                .help("User-Agent header sent with every request")
+                .value_name("UA")
+                .default_value(DEFAULT_USER_AGENT)
+                .global(true),
+        )
+        .arg(
+            Arg::new("retries")
+                .long("retries")
+                .help("Number of retries on timeouts and 5xx/429 responses")
+                .value_name("N")
+                .value_parser(value_parser!(u32))
+                .default_value("0")
+                .global(true),
+        )
+        .arg(
+            Arg::new("instances")
+                .long("instances")
+                .help("Comma-separated Invidious/Piped mirrors tried when youtube.com blocks the direct fetch")
+                .value_name("HOST,HOST,...")
+                .value_delimiter(',')
+                .default_value(default_instances_value())
+                .global(true),
+        )
         .subcommand(
             Command::new("url")
                 .about("Process a single YouTube URL")
@@ -232,34 +540,82 @@ fn cli() -> Command {
                         .required(true)
                         .value_parser(value_parser!(std::path::PathBuf))
                         .index(1),
+                )
+                .arg(
+                    Arg::new("format")
+                        .long("format")
+                        .help("Output format for the resolved feeds")
+                        .value_name("FORMAT")
+                        .value_parser(["urls", "opml"])
+                        .default_value("urls"),
                 ),
-        )
+        );
+
+    #[cfg(feature = "rss")]
+    let cmd = cmd.subcommand(
+        Command::new("feed")
+            .about("Resolve a channel's feed and parse its recent uploads")
+            .arg(
+                Arg::new("yt_channel_url")
+                    .help("YouTube channel URL to fetch recent uploads for")
+                    .value_name("YT_URL")
+                    .required(true)
+                    .index(1),
+            )
+            .arg(
+                Arg::new("output")
+                    .long("output")
+                    .help("Output format for the parsed video entries")
+                    .value_name("FORMAT")
+                    .value_parser(["json", "csv", "text"])
+                    .default_value("text"),
+            ),
+    );
+
+    cmd
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let matches = cli().get_matches();
-    let app = App::new();
+
+    let config = ClientConfig {
+        timeout: std::time::Duration::from_secs(*matches.get_one::<u64>("timeout").unwrap_or(&30)),
+        user_agent: matches
+            .get_one::<String>("user_agent")
+            .cloned()
+            .unwrap_or_else(|| DEFAULT_USER_AGENT.to_string()),
+        retries: *matches.get_one::<u32>("retries").unwrap_or(&0),
+        instances: matches
+            .get_many::<String>("instances")
+            .map(|values| values.cloned().collect())
+            .unwrap_or_else(|| DEFAULT_INSTANCES.iter().map(|s| s.to_string()).collect()),
+    };
+    let app = App::with_config(config);
 
     match matches.subcommand() {
         Some(("url", sub_matches)) => {
-            let rss_url = app
+            let feed = app
                 .run(
                     sub_matches
                         .get_one::<String>("yt_channel_url")
                         .expect("required"),
                 )
                 .await?;
-            Output::print(&rss_url);
+            Output::print(&feed.url);
         }
         Some(("file", sub_matches)) => {
             let file_path = sub_matches
                 .get_one::<std::path::PathBuf>("file_path")
                 .expect("required");
+            let format = sub_matches
+                .get_one::<String>("format")
+                .map(String::as_str)
+                .unwrap_or("urls");
 
             let results = app.run_file(file_path).await?;
 
-            let successful_urls: Vec<_> = results
+            let successful_feeds: Vec<_> = results
                 .iter()
                 .filter_map(|(_, result)| result.as_ref().ok().cloned())
                 .collect();
@@ -270,12 +626,29 @@ async fn main() -> Result<()> {
                 }
             }
 
-            if successful_urls.is_empty() {
+            if successful_feeds.is_empty() {
                 println!("No RSS feeds found.");
+            } else if format == "opml" {
+                Output::write_opml(file_path, &successful_feeds)?;
             } else {
-                Output::write_urls(file_path, &successful_urls)?;
+                let urls: Vec<_> = successful_feeds.into_iter().map(|feed| feed.url).collect();
+                Output::write_urls(file_path, &urls)?;
             }
         }
+        #[cfg(feature = "rss")]
+        Some(("feed", sub_matches)) => {
+            let url_str = sub_matches
+                .get_one::<String>("yt_channel_url")
+                .expect("required");
+            let output_format = sub_matches
+                .get_one::<String>("output")
+                .map(String::as_str)
+                .unwrap_or("text");
+
+            let resolved = app.run(url_str).await?;
+            let entries = feed::fetch_entries(&app.client, &resolved.url).await?;
+            println!("{}", feed::render(&entries, output_format));
+        }
         _ => unreachable!(),
     }
 
@@ -323,6 +696,101 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_extract_feed_url_falls_back_to_canonical_link() {
+        let html = r#"
+        <html>
+            <head>
+                <link rel="canonical" href="https://www.youtube.com/channel/UCabc123">
+            </head>
+        </html>
+        "#;
+
+        let result = HTMLParser::extract_feed_url(html);
+        assert_eq!(
+            result.unwrap(),
+            "https://www.youtube.com/feeds/videos.xml?channel_id=UCabc123"
+        );
+    }
+
+    #[test]
+    fn test_extract_feed_url_falls_back_to_og_url() {
+        let html = r#"
+        <html>
+            <head>
+                <meta property="og:url" content="https://www.youtube.com/channel/UCabc456">
+            </head>
+        </html>
+        "#;
+
+        let result = HTMLParser::extract_feed_url(html);
+        assert_eq!(
+            result.unwrap(),
+            "https://www.youtube.com/feeds/videos.xml?channel_id=UCabc456"
+        );
+    }
+
+    #[test]
+    fn test_extract_feed_url_falls_back_to_inline_json() {
+        let html = r#"<html><body><script>var ytInitialData = {"channelId":"UCabc789"};</script></body></html>"#;
+
+        let result = HTMLParser::extract_feed_url(html);
+        assert_eq!(
+            result.unwrap(),
+            "https://www.youtube.com/feeds/videos.xml?channel_id=UCabc789"
+        );
+    }
+
+    #[test]
+    fn test_playlist_id_from_url() {
+        let url = YoutubeUrl::new("https://www.youtube.com/playlist?list=PL1234").unwrap();
+        assert_eq!(url.playlist_id(), Some("PL1234".to_string()));
+    }
+
+    #[test]
+    fn test_playlist_id_absent() {
+        let url = YoutubeUrl::new("https://www.youtube.com/channel/UC1234").unwrap();
+        assert_eq!(url.playlist_id(), None);
+    }
+
+    #[test]
+    fn test_client_config_defaults() {
+        let config = ClientConfig::default();
+        assert_eq!(config.timeout, std::time::Duration::from_secs(30));
+        assert_eq!(config.retries, 0);
+        assert_eq!(config.user_agent, DEFAULT_USER_AGENT);
+        assert_eq!(config.instances.len(), DEFAULT_INSTANCES.len());
+    }
+
+    #[test]
+    fn test_extract_channel_id_from_html_for_mirror_page() {
+        let html = r#"
+        <html>
+            <head>
+                <meta property="og:url" content="https://yewtu.be/channel/UCmirror">
+            </head>
+        </html>
+        "#;
+
+        assert_eq!(
+            HTMLParser::extract_channel_id_from_html(html),
+            Some("UCmirror".to_string())
+        );
+    }
+
+    #[test]
+    fn test_is_retryable_server_errors_and_rate_limit() {
+        assert!(YoutubeClient::is_retryable(&AppError::HttpStatus(
+            reqwest::StatusCode::INTERNAL_SERVER_ERROR
+        )));
+        assert!(YoutubeClient::is_retryable(&AppError::HttpStatus(
+            reqwest::StatusCode::TOO_MANY_REQUESTS
+        )));
+        assert!(!YoutubeClient::is_retryable(&AppError::HttpStatus(
+            reqwest::StatusCode::NOT_FOUND
+        )));
+    }
+
     #[test]
     fn test_generate_output_filename_with_extension() {
         let path = PathBuf::from("/path/to/input.txt");
@@ -350,4 +818,54 @@ mod tests {
         let result = Output::generate_output_filename(&path);
         assert_eq!(result, PathBuf::from("archive.tar_parsed.gz"));
     }
+
+    #[test]
+    fn test_extract_channel_title() {
+        let html = r#"
+        <html>
+            <head>
+                <meta property="og:title" content="Some Channel">
+            </head>
+        </html>
+        "#;
+
+        assert_eq!(
+            HTMLParser::extract_channel_title(html),
+            Some("Some Channel".to_string())
+        );
+    }
+
+    #[test]
+    fn test_write_opml_escapes_and_falls_back_to_url() {
+        let dir = std::env::temp_dir().join("ytrss_test_write_opml");
+        std::fs::create_dir_all(&dir).unwrap();
+        let input_path = dir.join("channels.txt");
+
+        let feeds = vec![
+            ChannelFeed {
+                url: "https://www.youtube.com/feeds/videos.xml?channel_id=UC1".to_string(),
+                title: Some("Foo & Bar".to_string()),
+            },
+            ChannelFeed {
+                url: "https://www.youtube.com/feeds/videos.xml?playlist_id=PL1".to_string(),
+                title: None,
+            },
+        ];
+
+        Output::write_opml(&input_path, &feeds).unwrap();
+
+        let output_path = Output::generate_output_filename(&input_path).with_extension("opml");
+        let contents = std::fs::read_to_string(&output_path).unwrap();
+
+        assert!(contents.contains(r#"<opml version="2.0">"#));
+        assert!(contents.contains("Foo &amp; Bar"));
+        assert!(contents.contains(
+            r#"xmlUrl="https://www.youtube.com/feeds/videos.xml?playlist_id=PL1""#
+        ));
+        assert!(contents.contains(
+            r#"text="https://www.youtube.com/feeds/videos.xml?playlist_id=PL1""#
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }